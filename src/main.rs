@@ -1,15 +1,19 @@
 // #![windows_subsystem = "windows"]
 
-use daktilo_lib::{app::App, audio, embed::EmbeddedConfig};
+use clap::Parser;
+use daktilo_lib::{app::App, audio, config::SoundPreset, embed::EmbeddedConfig};
 use rdev::listen;
 use rodio::{cpal::traits::HostTrait, DeviceTrait};
 use serde::{Deserialize, Serialize};
-use std::sync::mpsc;
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::sync::{mpsc, Mutex};
 use tao::event_loop::{ControlFlow, EventLoopBuilder};
+use tracing::field::{Field, Visit};
 use tracing_subscriber::prelude::*;
 use tray_icon::{
-    menu::{CheckMenuItemBuilder, Menu, MenuEvent, MenuId, MenuItem, Submenu},
-    TrayIconBuilder,
+    menu::{CheckMenuItem, CheckMenuItemBuilder, Menu, MenuEvent, MenuId, MenuItem, Submenu},
+    TrayIconBuilder, TrayIconEvent,
 };
 
 const ICON_ENABLED: &[u8] = include_bytes!(concat!(
@@ -21,67 +25,296 @@ const ICON_DISABLED: &[u8] = include_bytes!(concat!(
     "/assets/typewritter_icon_disabled.png"
 ));
 
+/// User events funnelled into the `tao` event loop through its
+/// [`EventLoopProxy`](tao::event_loop::EventLoopProxy), so the loop wakes
+/// exactly when the tray produces something to handle.
+enum UserEvent {
+    Menu(MenuEvent),
+    /// A tray-icon interaction (click/enter/leave); forwarded so the loop
+    /// wakes on it too, even though we currently take no action.
+    Tray(TrayIconEvent),
+    /// The sound thread failed to (re)initialise audio; the tray switches to
+    /// the disabled icon and an error tooltip. The enabled state, the
+    /// enable/disable item and the menu check marks are left unchanged.
+    AudioError(String),
+}
+
+/// Number of trailing log lines the log window shows when it opens.
+const LOG_CAPACITY: usize = 500;
+
+/// A `tracing` layer that mirrors formatted records to a log file, so the
+/// tray can surface diagnostics even when built with the `windows`
+/// subsystem (where stdout is invisible). The file is the single source the
+/// "show log" window follows.
+struct LogFileLayer {
+    file: Option<Mutex<std::fs::File>>,
+}
+
+impl LogFileLayer {
+    fn new(log_path: &Path) -> Self {
+        let file = std::fs::File::create(log_path).ok().map(Mutex::new);
+        Self { file }
+    }
+}
+
+impl<S: tracing::Subscriber> tracing_subscriber::Layer<S> for LogFileLayer {
+    fn on_event(
+        &self,
+        event: &tracing::Event<'_>,
+        _ctx: tracing_subscriber::layer::Context<'_, S>,
+    ) {
+        let Some(file) = &self.file else {
+            return;
+        };
+        let mut message = String::new();
+        event.record(&mut MessageVisitor(&mut message));
+        let meta = event.metadata();
+        let line = format!("{:>5} {}: {}", meta.level(), meta.target(), message);
+        if let Ok(mut file) = file.lock() {
+            let _ = writeln!(file, "{line}");
+        }
+    }
+}
+
+/// Collects the `message` field of an event into a string.
+struct MessageVisitor<'a>(&'a mut String);
+
+impl<'a> Visit for MessageVisitor<'a> {
+    fn record_debug(&mut self, field: &Field, value: &dyn std::fmt::Debug) {
+        if field.name() == "message" {
+            use std::fmt::Write;
+            let _ = write!(self.0, "{value:?}");
+        }
+    }
+}
+
 enum EventKind {
     KeyEvent(rdev::Event),
     ChangeConfig {
-        preset_name: String,
+        preset: SoundPreset,
         device_name: String,
+        gain: f32,
     },
     Enabled(bool),
 }
 
+/// Volume percentages offered in the "volume" submenu.
+const VOLUME_STEPS: [u32; 5] = [25, 50, 75, 100, 150];
+
+fn default_volume() -> u32 {
+    100
+}
+
+/// Converts a volume percentage into the gain factor expected by
+/// [`App::init`].
+fn gain(volume: u32) -> f32 {
+    volume as f32 / 100.0
+}
+
+/// Lower-cased name of the default output device, if one can be resolved.
+/// Returns `None` on headless sessions or while the audio server is down.
+fn default_device_name() -> Option<String> {
+    rodio::cpal::default_host()
+        .default_output_device()
+        .and_then(|d| d.name().ok())
+        .map(|n| n.to_lowercase())
+}
+
+/// Command-line overrides for the initial [`State`], useful for autostart
+/// entries, per-profile shortcuts and portable installs.
+#[derive(Parser, Debug)]
+#[command(name = "daktilo-tray", version, about = "System tray for daktilo typewriter sounds")]
+struct Args {
+    /// Sound preset to start with (overrides the cached one).
+    #[arg(long)]
+    preset: Option<String>,
+    /// Output device to start with (overrides the cached one).
+    #[arg(long)]
+    device: Option<String>,
+    /// Start muted instead of playing sounds.
+    #[arg(long)]
+    disabled: bool,
+    /// Override the location of the cache TOML file.
+    #[arg(long)]
+    cache: Option<PathBuf>,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 struct State {
     enabled: bool,
     current_preset_name: String,
     current_device_name: String,
+    #[serde(default = "default_volume")]
+    volume: u32,
+}
+
+/// Directory scanned for user-provided `*.toml` sound presets.
+fn user_presets_dir() -> Option<PathBuf> {
+    directories::BaseDirs::new().map(|b| b.config_dir().join("daktilo_tray/presets"))
+}
+
+/// Builds the preset list by merging the embedded presets with any user
+/// presets found in [`user_presets_dir`]. A user preset overrides an
+/// embedded one sharing the same name.
+fn load_presets() -> Vec<SoundPreset> {
+    let mut presets = EmbeddedConfig::parse().unwrap().sound_presets;
+    let Some(dir) = user_presets_dir() else {
+        return presets;
+    };
+    let entries = match std::fs::read_dir(&dir) {
+        Ok(entries) => entries,
+        Err(e) => {
+            tracing::debug!("no user presets in {}: {e}", dir.display());
+            return presets;
+        }
+    };
+    for entry in entries.flatten() {
+        let path = entry.path();
+        if path.extension().and_then(|e| e.to_str()) != Some("toml") {
+            continue;
+        }
+        match std::fs::read_to_string(&path).map(|c| toml::from_str::<SoundPreset>(&c)) {
+            Ok(Ok(preset)) => {
+                tracing::debug!("loaded user preset {} from {}", preset.name, path.display());
+                presets.retain(|p| p.name != preset.name);
+                presets.push(preset);
+            }
+            Ok(Err(e)) => tracing::error!("could not parse preset {}: {e}", path.display()),
+            Err(e) => tracing::error!("could not read preset {}: {e}", path.display()),
+        }
+    }
+    presets
+}
+
+/// Rebuilds the `presets` submenu from the given preset list, replacing any
+/// existing items, and returns the freshly created check items. Used both at
+/// startup and when the user triggers "reload presets".
+fn rebuild_presets_menu(
+    menu: &Submenu,
+    presets: &[SoundPreset],
+    current: &str,
+) -> Vec<CheckMenuItem> {
+    use tray_icon::menu::ContextMenu;
+    for item in menu.items() {
+        let _ = menu.remove(item.as_ref());
+    }
+    let items: Vec<_> = presets
+        .iter()
+        .enumerate()
+        .map(|(i, p)| {
+            CheckMenuItemBuilder::new()
+                .id(MenuId(format!("preset_{i}")))
+                .text(&p.name)
+                .enabled(true)
+                .checked(p.name == current)
+                .build()
+        })
+        .collect();
+    for item in items.iter() {
+        menu.append(item).unwrap();
+    }
+    items
 }
 
 fn main() {
-    // Set up tracing
+    let args = Args::parse();
+
+    // Set up tracing. Records go to stdout (useful when launched from a
+    // console) and to a log file that backs the "show log" tray item.
+    let log_path = directories::BaseDirs::new()
+        .unwrap()
+        .cache_dir()
+        .join("daktilo_tray.log");
     tracing_subscriber::registry()
         .with(tracing_subscriber::fmt::layer())
+        .with(LogFileLayer::new(&log_path))
         .with(tracing_subscriber::EnvFilter::from_default_env())
         .init();
 
-    let presets = EmbeddedConfig::parse().unwrap().sound_presets;
-    let devices = audio::get_devices().expect("Fail to get computer audio devices");
+    let mut presets = load_presets();
+    let devices = audio::get_devices().unwrap_or_else(|e| {
+        tracing::error!("could not get computer audio devices: {e}");
+        Vec::new()
+    });
+    // Whether we can resolve any usable output device. When false the tray
+    // still comes up, but muted and with a greyed-out devices submenu.
+    let audio_available = !devices.is_empty() && default_device_name().is_some();
     let (tx, rx) = mpsc::channel();
 
     // App states
-    let cache_path = directories::BaseDirs::new()
-        .unwrap()
-        .cache_dir()
-        .join("daktilo_tray_cache.toml");
+    let cache_path = args.cache.clone().unwrap_or_else(|| {
+        directories::BaseDirs::new()
+            .unwrap()
+            .cache_dir()
+            .join("daktilo_tray_cache.toml")
+    });
     let mut state = if let Ok(content) = std::fs::read_to_string(&cache_path) {
         let mut cached_state: State = toml::from_str(&content).unwrap();
-        if !rodio::cpal::default_host()
+        let cached_present = rodio::cpal::default_host()
             .output_devices()
-            .unwrap()
-            .any(|d| {
-                d.name().unwrap_or_default().to_lowercase() == cached_state.current_device_name
+            .map(|mut devs| {
+                devs.any(|d| {
+                    d.name().unwrap_or_default().to_lowercase() == cached_state.current_device_name
+                })
             })
-        {
-            cached_state.current_device_name = rodio::cpal::default_host()
-                .default_output_device()
-                .unwrap()
-                .name()
-                .unwrap()
-                .to_lowercase();
+            .unwrap_or(false);
+        if !cached_present {
+            cached_state.current_device_name = default_device_name().unwrap_or_default();
         }
         cached_state
     } else {
         State {
             enabled: true,
             current_preset_name: String::from("default"),
-            current_device_name: rodio::cpal::default_host()
-                .default_output_device()
-                .unwrap()
-                .name()
-                .unwrap()
-                .to_lowercase(), // for whatever reason, the App::init check agains lowercase device name
+            // for whatever reason, the App::init check agains lowercase device name
+            current_device_name: default_device_name().unwrap_or_default(),
+            volume: default_volume(),
         }
     };
+    // No usable device: keep the tray alive but muted.
+    if !audio_available {
+        state.enabled = false;
+    }
+
+    // CLI values take precedence over the cached state. An unknown preset or
+    // device is reported clearly instead of panicking later in the sound
+    // thread's `.find(..).unwrap()`.
+    if let Some(preset) = &args.preset {
+        if !presets.iter().any(|p| p.name == *preset) {
+            eprintln!("error: unknown preset '{preset}'");
+            std::process::exit(1);
+        }
+        state.current_preset_name = preset.clone();
+    }
+    if let Some(device) = &args.device {
+        if !devices
+            .iter()
+            .any(|(name, _)| name.to_lowercase() == device.to_lowercase())
+        {
+            eprintln!("error: unknown device '{device}'");
+            std::process::exit(1);
+        }
+        state.current_device_name = device.to_lowercase();
+    }
+    if args.disabled {
+        state.enabled = false;
+    }
+
+    // Guard the preset lookup the sound thread relies on. A stale cached
+    // preset (e.g. a user `*.toml` that was since deleted) must not stop the
+    // tray from starting, so fall back to "default" rather than exiting; the
+    // hard error above is reserved for an explicitly wrong `--preset`.
+    if !presets.iter().any(|p| p.name == state.current_preset_name) {
+        tracing::warn!(
+            "preset '{}' not found, falling back to 'default'",
+            state.current_preset_name
+        );
+        state.current_preset_name = String::from("default");
+    }
+    if !presets.iter().any(|p| p.name == state.current_preset_name) {
+        eprintln!("error: preset '{}' not found", state.current_preset_name);
+        std::process::exit(1);
+    }
     tracing::debug!("{:?}", &state);
 
     // Spawn a thread to listen to key events
@@ -94,10 +327,14 @@ fn main() {
         .expect("could not listen events");
     });
 
+    let event_loop = EventLoopBuilder::<UserEvent>::with_user_event().build();
+
     // Spawn a thread to play sound
+    let audio_proxy = event_loop.create_proxy();
     let presets_clone = presets.clone();
     let init_device_name = state.current_device_name.clone();
     let init_preset_name = state.current_preset_name.clone();
+    let init_gain = gain(state.volume);
     let mut enabled = state.enabled;
     tracing::debug!("Current device: {}", state.current_device_name);
     std::thread::spawn(move || {
@@ -105,25 +342,38 @@ fn main() {
             .iter()
             .find(|p| p.name == init_preset_name)
             .unwrap();
-        let mut app = App::init(preset.clone(), None, Some(init_device_name)).unwrap();
+        // `None` when no device could be opened; the tray is notified and
+        // key events are simply dropped until audio recovers.
+        let mut app = match App::init(preset.clone(), Some(init_gain), Some(init_device_name)) {
+            Ok(app) => Some(app),
+            Err(e) => {
+                tracing::error!("could not initialize audio: {e}");
+                let _ = audio_proxy.send_event(UserEvent::AudioError(e.to_string()));
+                None
+            }
+        };
         loop {
             match rx.recv() {
                 Ok(EventKind::KeyEvent(event)) => {
                     if enabled {
-                        app.handle_key_event(event.clone()).unwrap()
+                        if let Some(app) = app.as_mut() {
+                            app.handle_key_event(event.clone()).unwrap_or_else(|e| {
+                                tracing::error!("could not play key event: {e}")
+                            });
+                        }
                     }
                 }
                 Ok(EventKind::ChangeConfig {
-                    preset_name,
+                    preset,
                     device_name,
-                }) => {
-                    let preset = presets_clone
-                        .iter()
-                        .find(|p| p.name == preset_name)
-                        .unwrap();
-                    app =
-                        App::init(preset.clone(), None, Some(device_name.to_lowercase())).unwrap();
-                }
+                    gain,
+                }) => match App::init(preset, Some(gain), Some(device_name.to_lowercase())) {
+                    Ok(new_app) => app = Some(new_app),
+                    Err(e) => {
+                        tracing::error!("could not reconfigure audio: {e}");
+                        let _ = audio_proxy.send_event(UserEvent::AudioError(e.to_string()));
+                    }
+                },
                 Ok(EventKind::Enabled(is_enabled)) => enabled = is_enabled,
                 Err(e) => {
                     tracing::error!("{}", e);
@@ -135,43 +385,66 @@ fn main() {
     let enabled_icon = load_icon(ICON_ENABLED);
     let disabled_icon = load_icon(ICON_DISABLED);
     let presets_menu = Submenu::new("presets", true);
-    let devices_menu = Submenu::new("devices", true);
-    let enable_menu = MenuItem::new(if state.enabled { "disable" } else { "enable" }, true, None);
+    // Greyed out when no output device is available.
+    let devices_menu = Submenu::new("devices", audio_available);
+    let volume_menu = Submenu::new("volume", true);
+    // Greyed out when no output device is available: toggling on would claim
+    // to be enabled while the sound thread has no audio `app` to play with.
+    let enable_menu = MenuItem::new(
+        if state.enabled { "disable" } else { "enable" },
+        audio_available,
+        None,
+    );
+    let reload_menu =
+        MenuItem::with_id(MenuId("reload_presets".to_string()), "reload presets", true, None);
+    let log_menu = MenuItem::with_id(MenuId("show_log".to_string()), "show log", true, None);
     let exit_menu = MenuItem::with_id(MenuId("exit".to_string()), "exit", true, None);
-    let preset_items: Vec<_> = presets
+    let mut preset_items =
+        rebuild_presets_menu(&presets_menu, &presets, &state.current_preset_name);
+    let device_items: Vec<_> = devices
         .iter()
         .enumerate()
-        .map(|(i, p)| {
+        .map(|(i, (name, _))| {
             CheckMenuItemBuilder::new()
-                .id(MenuId(format!("preset_{i}")))
-                .text(&p.name)
+                .id(MenuId(format!("device_{i}")))
+                .text(name)
                 .enabled(true)
-                .checked(p.name == state.current_preset_name)
+                .checked(name.to_lowercase() == state.current_device_name)
                 .build()
         })
         .collect();
-    for item in preset_items.iter() {
-        presets_menu.append(item).unwrap();
+    for item in device_items.iter() {
+        devices_menu.append(item).unwrap();
     }
-    let device_items: Vec<_> = devices
+    let volume_items: Vec<_> = VOLUME_STEPS
         .iter()
         .enumerate()
-        .map(|(i, (name, _))| {
+        .map(|(i, percent)| {
             CheckMenuItemBuilder::new()
-                .id(MenuId(format!("device_{i}")))
-                .text(name)
+                .id(MenuId(format!("volume_{i}")))
+                .text(format!("{percent}%"))
                 .enabled(true)
-                .checked(name.to_lowercase() == state.current_device_name)
+                .checked(*percent == state.volume)
                 .build()
         })
         .collect();
-    for item in device_items.iter() {
-        devices_menu.append(item).unwrap();
+    for item in volume_items.iter() {
+        volume_menu.append(item).unwrap();
     }
     let mut tray_icon = None;
 
-    let menu_channel = MenuEvent::receiver();
-    let event_loop = EventLoopBuilder::new().build();
+    // Route menu events through the loop's proxy so `ControlFlow::Wait` is
+    // woken precisely when an item is clicked instead of relying on polling
+    // `try_recv` on unrelated wakeups.
+    let proxy = event_loop.create_proxy();
+    MenuEvent::set_event_handler(Some(move |e| {
+        let _ = proxy.send_event(UserEvent::Menu(e));
+    }));
+    let tray_proxy = event_loop.create_proxy();
+    TrayIconEvent::set_event_handler(Some(move |e| {
+        let _ = tray_proxy.send_event(UserEvent::Tray(e));
+    }));
+
     let tx2 = tx.clone();
     event_loop.run(move |event, _, control_flow| {
         *control_flow = ControlFlow::Wait;
@@ -182,7 +455,15 @@ fn main() {
             // Creating tray icon
             let tray_menu = Menu::new();
             tray_menu
-                .append_items(&[&presets_menu, &devices_menu, &enable_menu, &exit_menu])
+                .append_items(&[
+                    &presets_menu,
+                    &devices_menu,
+                    &volume_menu,
+                    &reload_menu,
+                    &enable_menu,
+                    &log_menu,
+                    &exit_menu,
+                ])
                 .unwrap();
             tray_icon = Some(
                 TrayIconBuilder::new()
@@ -192,7 +473,11 @@ fn main() {
                     } else {
                         disabled_icon.clone()
                     })
-                    .with_tooltip("Daktilo Tray")
+                    .with_tooltip(if audio_available {
+                        "Daktilo Tray"
+                    } else {
+                        "Daktilo Tray (no audio device)"
+                    })
                     .build()
                     .unwrap(),
             );
@@ -208,7 +493,22 @@ fn main() {
             }
         }
 
-        if let Ok(event) = menu_channel.try_recv() {
+        // Surface audio failures reported by the sound thread so the tray
+        // reflects the degraded state instead of silently doing nothing.
+        if let tao::event::Event::UserEvent(UserEvent::AudioError(msg)) = &event {
+            tracing::error!("audio unavailable: {msg}");
+            if let Some(tray) = tray_icon.as_mut() {
+                let _ = tray.set_icon(Some(disabled_icon.clone()));
+                let _ = tray.set_tooltip(Some("Daktilo Tray (audio error)"));
+            }
+        }
+
+        // Tray clicks merely need to wake the loop; no action is taken yet.
+        if let tao::event::Event::UserEvent(UserEvent::Tray(_)) = &event {
+            return;
+        }
+
+        if let tao::event::Event::UserEvent(UserEvent::Menu(event)) = event {
             // Enable/disable app
             if event.id() == enable_menu.id() {
                 if state.enabled {
@@ -230,9 +530,26 @@ fn main() {
                 }
                 tx2.send(EventKind::Enabled(state.enabled)).unwrap();
             }
+            // Re-scan the user presets directory and rebuild the submenu.
+            else if event.id() == reload_menu.id() {
+                presets = load_presets();
+                preset_items =
+                    rebuild_presets_menu(&presets_menu, &presets, &state.current_preset_name);
+            }
+            // Open a live log window that follows the layer-maintained log file.
+            else if event.id() == log_menu.id() {
+                open_log_window(&log_path);
+            }
             // Exit app
             else if event.id() == exit_menu.id() {
-                std::fs::write(&cache_path, toml::to_string(&state).unwrap()).unwrap();
+                if let Some(parent) = cache_path.parent() {
+                    if let Err(e) = std::fs::create_dir_all(parent) {
+                        tracing::error!("could not create cache dir {}: {e}", parent.display());
+                    }
+                }
+                if let Err(e) = std::fs::write(&cache_path, toml::to_string(&state).unwrap()) {
+                    tracing::error!("could not write cache {}: {e}", cache_path.display());
+                }
                 *control_flow = ControlFlow::ExitWithCode(0);
             } else {
                 let MenuId(id) = event.id();
@@ -242,11 +559,16 @@ fn main() {
                     preset_items.iter().enumerate().for_each(|(i, p)| {
                         if i == checked_i {
                             state.current_preset_name = p.text();
-                            tx2.send(EventKind::ChangeConfig {
-                                preset_name: state.current_preset_name.clone(),
-                                device_name: state.current_device_name.clone(),
-                            })
-                            .unwrap();
+                            if let Some(preset) =
+                                presets.iter().find(|p| p.name == state.current_preset_name)
+                            {
+                                tx2.send(EventKind::ChangeConfig {
+                                    preset: preset.clone(),
+                                    device_name: state.current_device_name.clone(),
+                                    gain: gain(state.volume),
+                                })
+                                .unwrap();
+                            }
                         }
                         p.set_checked(i == checked_i);
                     });
@@ -257,23 +579,103 @@ fn main() {
                     device_items.iter().enumerate().for_each(|(i, d)| {
                         if i == checked_i {
                             state.current_device_name = d.text().to_lowercase();
-                            tx2.send(EventKind::ChangeConfig {
-                                preset_name: state.current_preset_name.clone(),
-                                device_name: state.current_device_name.clone(),
-                            })
-                            .unwrap();
+                            if let Some(preset) =
+                                presets.iter().find(|p| p.name == state.current_preset_name)
+                            {
+                                tx2.send(EventKind::ChangeConfig {
+                                    preset: preset.clone(),
+                                    device_name: state.current_device_name.clone(),
+                                    gain: gain(state.volume),
+                                })
+                                .unwrap();
+                            }
                         }
                         d.set_checked(i == checked_i)
                     });
+                }
+                // Change volume / gain
+                else if id.starts_with("volume_") {
+                    let checked_i: usize = (id.strip_prefix("volume_").unwrap()).parse().unwrap();
+                    volume_items.iter().enumerate().for_each(|(i, v)| {
+                        if i == checked_i {
+                            state.volume = VOLUME_STEPS[i];
+                            if let Some(preset) =
+                                presets.iter().find(|p| p.name == state.current_preset_name)
+                            {
+                                tx2.send(EventKind::ChangeConfig {
+                                    preset: preset.clone(),
+                                    device_name: state.current_device_name.clone(),
+                                    gain: gain(state.volume),
+                                })
+                                .unwrap();
+                            }
+                        }
+                        v.set_checked(i == checked_i)
+                    });
                 } else {
                     unreachable!();
                 }
             }
-            println!("{event:?}");
+            tracing::debug!("menu event: {event:?}");
         }
     });
 }
 
+/// Opens a window that follows the log file maintained by [`LogFileLayer`],
+/// showing the last [`LOG_CAPACITY`] lines and live-appending new ones. The
+/// layer is the sole writer, so the follower never sees a truncation gap.
+///
+/// Every platform path tails the file so the contract holds: PowerShell on
+/// Windows, a `Terminal` session driven by `osascript` on macOS, and the
+/// first available terminal emulator on Linux. Only when no terminal can be
+/// spawned does Linux fall back to `xdg-open`, which shows a static snapshot.
+fn open_log_window(log_path: &Path) {
+    let path = log_path.display().to_string();
+    let tail_lines = LOG_CAPACITY.to_string();
+    let spawn = |cmd: &str, args: &[&str]| {
+        std::process::Command::new(cmd)
+            .args(args)
+            .spawn()
+            .map(|_| ())
+    };
+    let result = if cfg!(target_os = "windows") {
+        spawn(
+            "cmd",
+            &[
+                "/C",
+                "start",
+                "Daktilo log",
+                "powershell",
+                "-NoExit",
+                "-Command",
+                &format!("Get-Content -Path '{path}' -Wait -Tail {LOG_CAPACITY}"),
+            ],
+        )
+    } else if cfg!(target_os = "macos") {
+        let script = format!(
+            "tell application \"Terminal\" to do script \"tail -n {LOG_CAPACITY} -f '{path}'\""
+        );
+        spawn("osascript", &["-e", &script])
+    } else {
+        spawn(
+            "x-terminal-emulator",
+            &["-e", "tail", "-n", &tail_lines, "-f", &path],
+        )
+        .or_else(|_| {
+            spawn(
+                "gnome-terminal",
+                &["--", "tail", "-n", &tail_lines, "-f", &path],
+            )
+        })
+        .or_else(|_| spawn("konsole", &["-e", "tail", "-n", &tail_lines, "-f", &path]))
+        .or_else(|_| spawn("xterm", &["-e", "tail", "-n", &tail_lines, "-f", &path]))
+        .or_else(|_| spawn("xdg-open", &[&path]))
+    };
+    if let Err(e) = result {
+        tracing::error!("could not open log window: {e}");
+    }
+}
+
 fn load_icon(bytes: &[u8]) -> tray_icon::Icon {
     let (icon_rgba, icon_width, icon_height) = {
         let image = image::load_from_memory(bytes)